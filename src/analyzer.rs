@@ -0,0 +1,328 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::log_parser::{detect_burst, LogParser};
+use crate::types::{FileSummary, FileType};
+
+pub struct TextAnalyzer {
+    content: String,
+    lines: Vec<String>,
+    stopwords: HashSet<String>,
+}
+
+impl TextAnalyzer {
+    /// Builds an analyzer with a caller-supplied stopword set, e.g. one
+    /// loaded from a `--stopwords` file instead of the bundled default
+    /// (see `stopwords::default_stopwords`).
+    pub fn with_stopwords(content: String, stopwords: HashSet<String>) -> Self {
+        let lines = content.lines().map(|s| s.to_string()).collect();
+        Self { content, lines, stopwords }
+    }
+
+    fn basic_stats(&self) -> (usize, usize, usize) {
+        let line_count = self.lines.len();
+        let word_count = self.content.split_whitespace().count();
+        let char_count = self.content.chars().count();
+        (line_count, word_count, char_count)
+    }
+
+    pub fn analyze_text(&self) -> FileSummary {
+        let (line_count, word_count, char_count) = self.basic_stats();
+        let mut insights = Vec::new();
+        let mut statistics = HashMap::new();
+
+        let word_freq = self.get_word_frequency();
+        let top_words: Vec<_> = word_freq.iter().filter(|(word, _)| word.len() > 3) .take(5).map(|(word, count)| format!("{} ({})", word, count)).collect();
+        if !top_words.is_empty() {
+            insights.push(format!("Most frequent words: {}", top_words.join(", ")));
+        }
+
+        let avg_word_len = if word_count > 0 {
+            self.content.split_whitespace().map(|w| w.chars().count()).sum::<usize>() as f64 / word_count as f64
+        } else {
+            0.0
+        };
+
+        statistics.insert("avg_word_length".to_string(), format!("{:.1}", avg_word_len));
+        statistics.insert("avg_line_length".to_string(), format!("{:.1}", if line_count > 0 { char_count as f64 / line_count as f64 } else { 0.0 }));
+
+        FileSummary {
+            file_type: FileType::Text,
+            line_count,
+            word_count,
+            char_count,
+            key_insights: insights,
+            statistics,
+            summary: Vec::new(),
+        }
+    }
+
+    pub fn analyze_markdown(&self) -> FileSummary {
+        let (line_count, word_count, char_count) = self.basic_stats();
+        let mut insights = Vec::new();
+        let mut statistics = HashMap::new();
+
+        let mut headers = Vec::new();
+        let mut links = 0;
+        let mut images = 0;
+        let mut code_blocks = 0;
+
+        for line in &self.lines {
+            let trimmed = line.trim();
+
+            if trimmed.starts_with('#') {
+                let level = trimmed.chars().take_while(|&c| c == '#').count();
+                headers.push((level, trimmed.trim_start_matches('#').trim().to_string()));
+            }
+
+            links += line.matches("](").count();
+            images += line.matches("![").count();
+            if trimmed.starts_with("```") {
+                code_blocks += 1;
+            }
+        }
+
+        if !headers.is_empty() {
+            let header_summary: Vec<_> = headers.iter().take(5).map(|(level, text)| format!("H{}: {}", level, text)).collect();
+            insights.push(format!("Document structure: {}", header_summary.join(", ")));
+        }
+
+        statistics.insert("headers".to_string(), headers.len().to_string());
+        statistics.insert("links".to_string(), links.to_string());
+        statistics.insert("images".to_string(), images.to_string());
+        statistics.insert("code_blocks".to_string(), (code_blocks / 2).to_string());
+
+        FileSummary {
+            file_type: FileType::Markdown,
+            line_count,
+            word_count,
+            char_count,
+            key_insights: insights,
+            statistics,
+            summary: Vec::new(),
+        }
+    }
+
+    pub fn analyze_log(&self) -> FileSummary {
+        let (line_count, word_count, char_count) = self.basic_stats();
+        let mut insights = Vec::new();
+        let mut statistics = HashMap::new();
+
+        let parser = LogParser::new();
+        let mut log_levels = HashMap::new();
+        let mut timestamps = Vec::new();
+        let mut formats_seen = HashSet::new();
+        let mut errors = Vec::new();
+        let mut error_timestamps = Vec::new();
+
+        for line in &self.lines {
+            let parsed = parser.parse_line(line);
+
+            if let Some(level) = &parsed.level {
+                *log_levels.entry(level.clone()).or_insert(0) += 1;
+            }
+
+            let is_error = parsed.level.as_deref() == Some("ERROR") || parsed.is_error_marker;
+            if is_error {
+                errors.push(line.clone());
+                if let Some((_, ts, _)) = &parsed.timestamp {
+                    error_timestamps.push(*ts);
+                }
+            }
+
+            if let Some((raw, ts, format_name)) = parsed.timestamp {
+                formats_seen.insert(format_name);
+                timestamps.push((raw, ts));
+            }
+        }
+
+        if !log_levels.is_empty() {
+            let level_summary: Vec<_> = log_levels.iter().map(|(level, count)| format!("{}: {}", level, count)).collect();
+            insights.push(format!("Log levels: {}", level_summary.join(", ")));
+        }
+
+        if let (Some(min), Some(max)) = (
+            timestamps.iter().min_by_key(|(_, ts)| *ts),
+            timestamps.iter().max_by_key(|(_, ts)| *ts),
+        ) {
+            let mut formats: Vec<_> = formats_seen.iter().copied().collect();
+            formats.sort_unstable();
+            insights.push(format!("Time range: {} to {} (formats: {})", min.0, max.0, formats.join(", ")));
+        }
+
+        let error_rate = if line_count > 0 { errors.len() as f64 / line_count as f64 * 100.0 } else { 0.0 };
+        if !errors.is_empty() {
+            let error_sample = if errors.len() > 3 { &errors[0..3] } else { &errors };
+            insights.push(format!("Sample errors found: {} total ({:.1}% error rate)", errors.len(), error_rate));
+            for (i, error) in error_sample.iter().enumerate() {
+                if error.len() > 100 {
+                    insights.push(format!("  {}: {}...", i + 1, error.chars().take(100).collect::<String>()));
+                } else {
+                    insights.push(format!("  {}: {}", i + 1, error));
+                }
+            }
+        }
+
+        if let Some((count, window)) = detect_burst(error_timestamps) {
+            insights.push(format!("Burst detected: {} errors within {}s", count, window));
+        }
+
+        statistics.insert("unique_timestamps".to_string(),
+            timestamps.into_iter().map(|(raw, _)| raw).collect::<HashSet<_>>().len().to_string());
+        statistics.insert("errors".to_string(), errors.len().to_string());
+        statistics.insert("error_rate".to_string(), format!("{:.1}%", error_rate));
+
+        FileSummary {
+            file_type: FileType::Log,
+            line_count,
+            word_count,
+            char_count,
+            key_insights: insights,
+            statistics,
+            summary: Vec::new(),
+        }
+    }
+
+    pub fn analyze_rust_code(&self) -> FileSummary {
+        let (line_count, word_count, char_count) = self.basic_stats();
+        let mut insights = Vec::new();
+        let mut statistics = HashMap::new();
+
+        let mut functions = Vec::new();
+        let mut structs = Vec::new();
+        let mut enums = Vec::new();
+        let mut imports = Vec::new();
+        let mut comments = 0;
+        let mut todo_count = 0;
+
+        for line in &self.lines {
+            let trimmed = line.trim();
+            if trimmed.starts_with("fn ") || trimmed.contains(" fn ") {
+                if let Some(name_start) = trimmed.find("fn ") {
+                    let name_part = &trimmed[name_start + 3..];
+                    if let Some(paren_pos) = name_part.find('(') {
+                        functions.push(name_part[..paren_pos].trim().to_string());
+                    }
+                }
+            }
+
+            if trimmed.starts_with("struct ") {
+                if let Some(name) = trimmed.split_whitespace().nth(1) {
+                    structs.push(name.to_string());
+                }
+            }
+            if trimmed.starts_with("enum ") {
+                if let Some(name) = trimmed.split_whitespace().nth(1) {
+                    enums.push(name.to_string());
+                }
+            }
+
+            if trimmed.starts_with("use ") {
+                imports.push(trimmed.to_string());
+            }
+
+            if trimmed.starts_with("//") || trimmed.starts_with("/*") {
+                comments += 1;
+            }
+            if trimmed.to_uppercase().contains("TODO") || trimmed.to_uppercase().contains("FIXME") {
+                todo_count += 1;
+            }
+        }
+
+        if !functions.is_empty() {
+            let func_sample: Vec<_> = functions.iter().take(5).collect();
+            insights.push(format!("Functions ({}): {}", functions.len(), func_sample.iter().map(|s| s.as_str()).collect::<Vec<_>>().join(", ")));
+        }
+
+        if !structs.is_empty() {
+            insights.push(format!("Structs: {}", structs.join(", ")));
+        }
+
+        if !enums.is_empty() {
+            insights.push(format!("Enums: {}", enums.join(", ")));
+        }
+
+        if todo_count > 0 {
+            insights.push(format!("TODOs/FIXMEs found: {}", todo_count));
+        }
+
+        let comment_ratio = if line_count > 0 {
+            comments as f64 / line_count as f64 * 100.0
+        } else { 0.0 };
+
+        statistics.insert("functions".to_string(), functions.len().to_string());
+        statistics.insert("structs".to_string(), structs.len().to_string());
+        statistics.insert("enums".to_string(), enums.len().to_string());
+        statistics.insert("imports".to_string(), imports.len().to_string());
+        statistics.insert("comment_ratio".to_string(), format!("{:.1}%", comment_ratio));
+        statistics.insert("todos".to_string(), todo_count.to_string());
+
+        FileSummary {
+            file_type: FileType::RustCode,
+            line_count,
+            word_count,
+            char_count,
+            key_insights: insights,
+            statistics,
+            summary: Vec::new(),
+        }
+    }
+
+    /// Counts word occurrences, dropping stopwords, and ranks the result
+    /// by a TF-style weight (raw frequency scaled by word length) rather
+    /// than raw frequency alone, so short filler words that slip past the
+    /// stopword filter don't dominate. The returned counts themselves
+    /// stay raw, since `analyze_text` and `crate::summarize` both need
+    /// true frequencies, not weights.
+    pub fn get_word_frequency(&self) -> Vec<(String, usize)> {
+        let mut word_count = HashMap::new();
+
+        for word in self.content.split_whitespace() {
+            let clean_word = word.to_lowercase().chars().filter(|c| c.is_alphabetic()).collect::<String>();
+            if !clean_word.is_empty() && clean_word.len() > 2 && !self.stopwords.contains(&clean_word) {
+                *word_count.entry(clean_word).or_insert(0) += 1;
+            }
+        }
+
+        let mut sorted_words: Vec<_> = word_count.into_iter().collect();
+        sorted_words.sort_by(|a, b| {
+            tf_weight(&b.0, b.1)
+                .partial_cmp(&tf_weight(&a.0, a.1))
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.0.cmp(&b.0))
+        });
+        sorted_words
+    }
+
+    pub fn analyze(&self, file_type: FileType) -> FileSummary {
+        match file_type {
+            FileType::Text => self.analyze_text(),
+            FileType::Markdown => self.analyze_markdown(),
+            FileType::Log => self.analyze_log(),
+            FileType::RustCode => self.analyze_rust_code(),
+            FileType::Unknown => self.analyze_text(),
+        }
+    }
+
+    /// Selects the `summary_lines` most salient sentences via
+    /// `crate::summarize`. Log files have no prose to extract from, so
+    /// their summary is always empty.
+    pub fn extractive_summary(&self, file_type: FileType, summary_lines: usize) -> Vec<String> {
+        if file_type == FileType::Log {
+            return Vec::new();
+        }
+        let word_freq: HashMap<String, usize> = self.get_word_frequency().into_iter().collect();
+        crate::summarize::summarize(&self.content, file_type, &word_freq, summary_lines)
+    }
+
+    pub fn analyze_with_summary(&self, file_type: FileType, summary_lines: usize) -> FileSummary {
+        let mut result = self.analyze(file_type);
+        result.summary = self.extractive_summary(file_type, summary_lines);
+        result
+    }
+}
+
+/// Raw frequency scaled by a length factor so longer, more specific words
+/// outrank short ones that happened to slip past the stopword filter.
+fn tf_weight(word: &str, count: usize) -> f64 {
+    count as f64 * (word.chars().count() as f64).ln_1p()
+}
@@ -0,0 +1,276 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+use crate::analyzer::TextAnalyzer;
+use crate::output::{self, OutputFormat};
+use crate::stopwords;
+use crate::summarize::DEFAULT_SUMMARY_LINES;
+use crate::types::FileType;
+
+const DEFAULT_WATCH_INTERVAL_MS: u64 = 500;
+
+pub struct Cli {
+    pub command: Command,
+    pub verbosity: u8,
+    pub format: OutputFormat,
+    pub stopwords: HashSet<String>,
+}
+
+pub enum Command {
+    /// `summarize <files...>` — the default subcommand.
+    Summarize { files: Vec<PathBuf>, type_override: Option<FileType>, summary_lines: usize },
+    /// `stats <path>` — recursive, aggregate analysis of a directory tree.
+    Stats { path: PathBuf },
+    /// `watch <path>` — re-run `summarize` every time the file changes.
+    Watch { path: PathBuf },
+}
+
+pub fn usage() -> String {
+    "Usage: text-summarizer [-v|--verbose] [--verbosity <n>] [--format <text|json|ndjson>] <command> [args]\n\n\
+     Commands:\n\
+     \x20 summarize <files...> [--type <txt|md|log|rs>] [--summary-lines <n>]   Summarize one or more files (supports globs)\n\
+     \x20 stats <path>                                    Recursively analyze a directory tree\n\
+     \x20 watch <path>                                    Re-summarize a file whenever it changes\n\n\
+     Global flags:\n\
+     \x20 --stopwords <file>   Replace the bundled stopword list used by word-frequency scoring\n\n\
+     Supported file types: .txt, .md, .log, .rs".to_string()
+}
+
+pub fn parse(args: &[String]) -> Result<Cli, String> {
+    let mut verbosity = 0u8;
+    let mut format = OutputFormat::Text;
+    let mut stopwords_set = stopwords::default_stopwords();
+    let mut rest = Vec::new();
+
+    let mut iter = args.iter().peekable();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "-v" | "--verbose" => verbosity += 1,
+            "--verbosity" => {
+                let value = iter.next().ok_or("--verbosity requires a value")?;
+                verbosity = value.parse().map_err(|_| format!("invalid --verbosity value: {}", value))?;
+            }
+            "--format" => {
+                let value = iter.next().ok_or("--format requires a value")?;
+                format = OutputFormat::from_flag(value).ok_or_else(|| format!("unsupported --format: {}", value))?;
+            }
+            "--stopwords" => {
+                let value = iter.next().ok_or("--stopwords requires a file path")?;
+                stopwords_set = stopwords::load_stopwords(Path::new(value))?;
+            }
+            other => rest.push(other.to_string()),
+        }
+    }
+
+    if rest.is_empty() {
+        return Err("no command given".to_string());
+    }
+
+    let command_name = rest.remove(0);
+    let command = match command_name.as_str() {
+        "summarize" => parse_summarize(rest)?,
+        "stats" => {
+            let path = rest.first().ok_or("stats requires a directory path")?;
+            Command::Stats { path: PathBuf::from(path) }
+        }
+        "watch" => {
+            let path = rest.first().ok_or("watch requires a file path")?;
+            Command::Watch { path: PathBuf::from(path) }
+        }
+        // Bare `text-summarizer <file>` keeps working as shorthand for `summarize <file>`.
+        other => parse_summarize(std::iter::once(other.to_string()).chain(rest).collect())?,
+    };
+
+    Ok(Cli { command, verbosity, format, stopwords: stopwords_set })
+}
+
+fn parse_summarize(args: Vec<String>) -> Result<Command, String> {
+    let mut type_override = None;
+    let mut summary_lines = DEFAULT_SUMMARY_LINES;
+    let mut file_args = Vec::new();
+
+    let mut iter = args.into_iter().peekable();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--type" => {
+                let value = iter.next().ok_or("--type requires a value")?;
+                type_override = Some(FileType::from_type_flag(&value).ok_or_else(|| format!("unknown --type: {}", value))?);
+            }
+            "--summary-lines" => {
+                let value = iter.next().ok_or("--summary-lines requires a value")?;
+                summary_lines = value.parse().map_err(|_| format!("invalid --summary-lines value: {}", value))?;
+            }
+            other => file_args.push(other.to_string()),
+        }
+    }
+
+    if file_args.is_empty() {
+        return Err("summarize requires at least one file".to_string());
+    }
+
+    let mut files = Vec::new();
+    for pattern in &file_args {
+        files.extend(expand_glob(pattern)?);
+    }
+
+    Ok(Command::Summarize { files, type_override, summary_lines })
+}
+
+/// Expands a single path argument that may contain a `*` wildcard in its
+/// final component, e.g. `src/*.rs`. Patterns without a wildcard are
+/// returned as-is so plain file paths keep working unchanged.
+fn expand_glob(pattern: &str) -> Result<Vec<PathBuf>, String> {
+    if !pattern.contains('*') {
+        return Ok(vec![PathBuf::from(pattern)]);
+    }
+
+    let path = Path::new(pattern);
+    let dir = path.parent().filter(|p| !p.as_os_str().is_empty()).unwrap_or(Path::new("."));
+    let file_pattern = path.file_name().and_then(|s| s.to_str()).unwrap_or("*");
+    let (prefix, suffix) = file_pattern.split_once('*').unwrap_or((file_pattern, ""));
+
+    let entries = std::fs::read_dir(dir).map_err(|e| format!("cannot read '{}': {}", dir.display(), e))?;
+    let mut matches = Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(|e| e.to_string())?;
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if name.starts_with(prefix) && name.ends_with(suffix) && entry.path().is_file() {
+            matches.push(entry.path());
+        }
+    }
+    matches.sort();
+    if matches.is_empty() {
+        return Err(format!("no files matched pattern '{}'", pattern));
+    }
+    Ok(matches)
+}
+
+pub fn run(cli: Cli) -> Result<(), String> {
+    match cli.command {
+        Command::Summarize { files, type_override, summary_lines } => {
+            run_summarize(&files, type_override, summary_lines, cli.format, cli.verbosity, &cli.stopwords)
+        }
+        Command::Stats { path } => run_stats(&path, cli.format, cli.verbosity, &cli.stopwords),
+        Command::Watch { path } => run_watch(&path, cli.format, cli.verbosity, &cli.stopwords),
+    }
+}
+
+fn summarize_one(path: &Path, type_override: Option<FileType>, summary_lines: usize, format: OutputFormat, stopwords: &HashSet<String>) -> Result<(), String> {
+    if !path.exists() {
+        return Err(format!("File '{}' does not exist.", path.display()));
+    }
+
+    let content = std::fs::read_to_string(path).map_err(|e| format!("error reading '{}': {}", path.display(), e))?;
+    let file_type = type_override.unwrap_or_else(|| FileType::from_extension(path));
+    let analyzer = TextAnalyzer::with_stopwords(content, stopwords.clone());
+    let summary = analyzer.analyze_with_summary(file_type, summary_lines);
+
+    output::render_summary(&summary, &path.display().to_string(), format);
+    Ok(())
+}
+
+fn run_summarize(files: &[PathBuf], type_override: Option<FileType>, summary_lines: usize, format: OutputFormat, verbosity: u8, stopwords: &HashSet<String>) -> Result<(), String> {
+    for path in files {
+        if verbosity > 0 {
+            eprintln!("[verbose] analyzing {}", path.display());
+        }
+        summarize_one(path, type_override, summary_lines, format, stopwords)?;
+        if format == OutputFormat::Text {
+            println!();
+        }
+    }
+    Ok(())
+}
+
+fn run_stats(path: &Path, format: OutputFormat, verbosity: u8, stopwords: &HashSet<String>) -> Result<(), String> {
+    if !path.is_dir() {
+        return Err(format!("'{}' is not a directory", path.display()));
+    }
+
+    let report = crate::stats::walk(path, stopwords, |i, total, file| {
+        if verbosity > 0 {
+            eprintln!("[verbose] ({}/{}) {}", i, total, file.display());
+        } else {
+            eprint!("\rAnalyzing file {}/{}...", i, total);
+        }
+    })?;
+    if verbosity == 0 {
+        eprintln!();
+    }
+
+    output::render_aggregate(&report, format);
+    Ok(())
+}
+
+fn run_watch(path: &Path, format: OutputFormat, verbosity: u8, stopwords: &HashSet<String>) -> Result<(), String> {
+    let mut last_modified = std::fs::metadata(path).and_then(|m| m.modified()).map_err(|e| e.to_string())?;
+    summarize_one(path, None, DEFAULT_SUMMARY_LINES, format, stopwords)?;
+
+    loop {
+        thread::sleep(Duration::from_millis(DEFAULT_WATCH_INTERVAL_MS));
+        let modified = match std::fs::metadata(path).and_then(|m| m.modified()) {
+            Ok(m) => m,
+            Err(e) => {
+                eprintln!("error reading '{}': {}", path.display(), e);
+                continue;
+            }
+        };
+        if modified != last_modified {
+            last_modified = modified;
+            if verbosity > 0 {
+                eprintln!("[verbose] change detected in {}", path.display());
+            }
+            if format == OutputFormat::Text {
+                println!();
+            }
+            if let Err(e) = summarize_one(path, None, DEFAULT_SUMMARY_LINES, format, stopwords) {
+                eprintln!("{}", e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expand_glob_passes_through_patterns_without_wildcard() {
+        let result = expand_glob("src/cli.rs").unwrap();
+        assert_eq!(result, vec![PathBuf::from("src/cli.rs")]);
+    }
+
+    #[test]
+    fn expand_glob_matches_prefix_and_suffix() {
+        let dir = std::env::temp_dir().join("text-summarizer-test-glob");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("report_a.log"), "").unwrap();
+        std::fs::write(dir.join("report_b.log"), "").unwrap();
+        std::fs::write(dir.join("notes.txt"), "").unwrap();
+
+        let pattern = dir.join("report_*.log");
+        let mut matches = expand_glob(pattern.to_str().unwrap()).unwrap();
+        matches.sort();
+
+        let mut expected = vec![dir.join("report_a.log"), dir.join("report_b.log")];
+        expected.sort();
+
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(matches, expected);
+    }
+
+    #[test]
+    fn expand_glob_errors_when_nothing_matches() {
+        let dir = std::env::temp_dir().join("text-summarizer-test-glob-empty");
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let pattern = dir.join("nope_*.log");
+        let result = expand_glob(pattern.to_str().unwrap());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,200 @@
+use regex::{Captures, Regex};
+
+pub type ToSeconds = Box<dyn Fn(&Captures) -> Option<i64> + Send + Sync>;
+
+/// A timestamp parsed from a log line, reduced to a single comparable
+/// value so lines can be ordered and diffed regardless of which source
+/// format they came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ParsedTimestamp {
+    pub seconds: i64,
+}
+
+/// One recognized timestamp format: a regex plus the logic to reduce a
+/// match into a `ParsedTimestamp`. The pattern set is public so callers
+/// can append formats beyond the built-in ISO-8601 / syslog / bracketed
+/// set via `LogParser::with_patterns`.
+pub struct TimestampPattern {
+    pub name: &'static str,
+    pub regex: Regex,
+    pub to_seconds: ToSeconds,
+}
+
+impl TimestampPattern {
+    fn try_match(&self, line: &str) -> Option<(String, ParsedTimestamp, &'static str)> {
+        let caps = self.regex.captures(line)?;
+        let seconds = (self.to_seconds)(&caps)?;
+        let raw = caps.get(0)?.as_str().to_string();
+        Some((raw, ParsedTimestamp { seconds }, self.name))
+    }
+}
+
+fn digits(caps: &Captures, idx: usize) -> Option<i64> {
+    caps.get(idx)?.as_str().parse().ok()
+}
+
+fn month_number(name: &str) -> Option<i64> {
+    const MONTHS: [&str; 12] = ["jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec"];
+    let lower = name.to_lowercase();
+    MONTHS.iter().position(|m| *m == lower).map(|i| i as i64 + 1)
+}
+
+/// Folds year/month/day/hour/min/sec into a single monotonically
+/// increasing value. Not a real calendar (months are treated as 31 days),
+/// which is fine since it is only ever used to order and diff timestamps
+/// within the same log.
+fn fold(year: i64, month: i64, day: i64, hour: i64, min: i64, sec: i64) -> i64 {
+    ((((year * 12 + month) * 31 + day) * 24 + hour) * 60 + min) * 60 + sec
+}
+
+/// Builds the default set of recognized timestamp formats: ISO-8601
+/// (`2024-01-02T15:04:05`), bracketed (`[2024-01-02 15:04:05]`), and
+/// syslog (`Jan 2 15:04:05`).
+pub fn default_timestamp_patterns() -> Vec<TimestampPattern> {
+    vec![
+        TimestampPattern {
+            name: "iso8601",
+            regex: Regex::new(r"(\d{4})-(\d{2})-(\d{2})T(\d{2}):(\d{2}):(\d{2})").unwrap(),
+            to_seconds: Box::new(|caps| {
+                Some(fold(digits(caps, 1)?, digits(caps, 2)?, digits(caps, 3)?, digits(caps, 4)?, digits(caps, 5)?, digits(caps, 6)?))
+            }),
+        },
+        TimestampPattern {
+            name: "bracketed",
+            regex: Regex::new(r"\[(\d{4})-(\d{2})-(\d{2}) (\d{2}):(\d{2}):(\d{2})\]").unwrap(),
+            to_seconds: Box::new(|caps| {
+                Some(fold(digits(caps, 1)?, digits(caps, 2)?, digits(caps, 3)?, digits(caps, 4)?, digits(caps, 5)?, digits(caps, 6)?))
+            }),
+        },
+        TimestampPattern {
+            name: "syslog",
+            regex: Regex::new(r"(?i)\b(Jan|Feb|Mar|Apr|May|Jun|Jul|Aug|Sep|Oct|Nov|Dec)\s+(\d{1,2})\s+(\d{2}):(\d{2}):(\d{2})").unwrap(),
+            to_seconds: Box::new(|caps| {
+                let month = month_number(caps.get(1)?.as_str())?;
+                Some(fold(0, month, digits(caps, 2)?, digits(caps, 3)?, digits(caps, 4)?, digits(caps, 5)?))
+            }),
+        },
+    ]
+}
+
+/// A log line's level token and parsed timestamp, if either was found.
+/// `timestamp` carries the raw matched text, the comparable value, and
+/// the name of the format that matched (see `TimestampPattern::name`).
+pub struct ParsedLine {
+    pub level: Option<String>,
+    pub timestamp: Option<(String, ParsedTimestamp, &'static str)>,
+    /// Whether the line contains a whole-word error marker (`EXCEPTION`,
+    /// `FAIL`, `FAILED`, `FAILURE`) independent of `level`, so lines like
+    /// "operation failed" are still classified as errors even without an
+    /// explicit `ERROR` level token.
+    pub is_error_marker: bool,
+}
+
+pub struct LogParser {
+    timestamp_patterns: Vec<TimestampPattern>,
+    level_pattern: Regex,
+    error_marker_pattern: Regex,
+}
+
+impl LogParser {
+    pub fn new() -> Self {
+        Self::with_patterns(default_timestamp_patterns())
+    }
+
+    /// Builds a parser around a caller-supplied pattern set, e.g. the
+    /// defaults plus application-specific formats.
+    pub fn with_patterns(timestamp_patterns: Vec<TimestampPattern>) -> Self {
+        Self {
+            timestamp_patterns,
+            level_pattern: Regex::new(r"(?i)\b(ERROR|WARN|WARNING|INFO|DEBUG|TRACE)\b").unwrap(),
+            error_marker_pattern: Regex::new(r"(?i)\b(EXCEPTION|FAILED|FAILURE|FAIL)\b").unwrap(),
+        }
+    }
+
+    /// Exposes the compiled pattern set read-only so embedders can
+    /// inspect it, e.g. to report which formats are active. Extra formats
+    /// are added by building a parser with `with_patterns`. Not called
+    /// anywhere in this binary.
+    #[allow(dead_code)]
+    pub fn timestamp_patterns(&self) -> &[TimestampPattern] {
+        &self.timestamp_patterns
+    }
+
+    pub fn parse_line(&self, line: &str) -> ParsedLine {
+        let level = self.level_pattern.captures(line).and_then(|c| c.get(1)).map(|m| m.as_str().to_uppercase());
+        let timestamp = self.timestamp_patterns.iter().find_map(|p| p.try_match(line));
+        let is_error_marker = self.error_marker_pattern.is_match(line);
+        ParsedLine { level, timestamp, is_error_marker }
+    }
+}
+
+impl Default for LogParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub const BURST_WINDOW_SECONDS: i64 = 60;
+pub const BURST_THRESHOLD: usize = 3;
+
+/// Scans for the first window of `BURST_WINDOW_SECONDS` containing at
+/// least `BURST_THRESHOLD` timestamps, returning `(count, window_secs)`.
+pub fn detect_burst(mut timestamps: Vec<ParsedTimestamp>) -> Option<(usize, i64)> {
+    if timestamps.len() < BURST_THRESHOLD {
+        return None;
+    }
+    timestamps.sort();
+    for i in 0..timestamps.len() {
+        let start = timestamps[i].seconds;
+        let count = timestamps[i..].iter().take_while(|t| t.seconds - start <= BURST_WINDOW_SECONDS).count();
+        if count >= BURST_THRESHOLD {
+            return Some((count, BURST_WINDOW_SECONDS));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn level_pattern_matches_whole_words_case_insensitively() {
+        let parser = LogParser::new();
+        let parsed = parser.parse_line("2024-01-02T15:04:05 error something broke");
+        assert_eq!(parsed.level.as_deref(), Some("ERROR"));
+    }
+
+    #[test]
+    fn parse_line_extracts_iso8601_timestamp() {
+        let parser = LogParser::new();
+        let parsed = parser.parse_line("2024-01-02T15:04:05 info started up");
+        let (raw, _, format_name) = parsed.timestamp.expect("expected a timestamp match");
+        assert_eq!(raw, "2024-01-02T15:04:05");
+        assert_eq!(format_name, "iso8601");
+    }
+
+    #[test]
+    fn parse_line_extracts_syslog_timestamp() {
+        let parser = LogParser::new();
+        let parsed = parser.parse_line("Jan 2 15:04:05 host service started");
+        let (_, _, format_name) = parsed.timestamp.expect("expected a timestamp match");
+        assert_eq!(format_name, "syslog");
+    }
+
+    #[test]
+    fn detect_burst_below_threshold_returns_none() {
+        let timestamps = vec![ParsedTimestamp { seconds: 0 }, ParsedTimestamp { seconds: 10 }];
+        assert_eq!(detect_burst(timestamps), None);
+    }
+
+    #[test]
+    fn detect_burst_finds_window_meeting_threshold() {
+        let timestamps = vec![
+            ParsedTimestamp { seconds: 0 },
+            ParsedTimestamp { seconds: 20 },
+            ParsedTimestamp { seconds: 40 },
+        ];
+        assert_eq!(detect_burst(timestamps), Some((3, BURST_WINDOW_SECONDS)));
+    }
+}
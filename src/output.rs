@@ -0,0 +1,124 @@
+use serde::Serialize;
+
+use crate::stats::AggregateReport;
+use crate::types::{FileSummary, FileType};
+
+/// Rendering format selected via the global `--format` flag.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+    Ndjson,
+}
+
+impl OutputFormat {
+    pub fn from_flag(s: &str) -> Option<Self> {
+        match s {
+            "text" => Some(OutputFormat::Text),
+            "json" => Some(OutputFormat::Json),
+            "ndjson" => Some(OutputFormat::Ndjson),
+            _ => None,
+        }
+    }
+}
+
+/// A `FileSummary` paired with the filename it was produced from, which is
+/// what actually gets serialized — `FileSummary` itself doesn't know its
+/// own source path.
+#[derive(Serialize)]
+struct SummaryRecord<'a> {
+    file: &'a str,
+    #[serde(flatten)]
+    summary: &'a FileSummary,
+}
+
+pub fn render_summary(summary: &FileSummary, filename: &str, format: OutputFormat) {
+    let record = SummaryRecord { file: filename, summary };
+    match format {
+        OutputFormat::Text => print_summary(summary, filename),
+        OutputFormat::Json => match serde_json::to_string_pretty(&record) {
+            Ok(json) => println!("{}", json),
+            Err(e) => eprintln!("error serializing summary for '{}': {}", filename, e),
+        },
+        OutputFormat::Ndjson => match serde_json::to_string(&record) {
+            Ok(json) => println!("{}", json),
+            Err(e) => eprintln!("error serializing summary for '{}': {}", filename, e),
+        },
+    }
+}
+
+pub fn render_aggregate(report: &AggregateReport, format: OutputFormat) {
+    match format {
+        OutputFormat::Text => print_aggregate(report),
+        OutputFormat::Json => match serde_json::to_string_pretty(report) {
+            Ok(json) => println!("{}", json),
+            Err(e) => eprintln!("error serializing aggregate report: {}", e),
+        },
+        OutputFormat::Ndjson => match serde_json::to_string(report) {
+            Ok(json) => println!("{}", json),
+            Err(e) => eprintln!("error serializing aggregate report: {}", e),
+        },
+    }
+}
+
+fn print_aggregate(report: &AggregateReport) {
+    println!("Aggregate Report");
+    println!("Files processed: {}", report.files_processed);
+
+    println!("\nBy file type:");
+    for (label, count) in &report.files_by_type {
+        let lines = report.lines_by_type.get(label).copied().unwrap_or(0);
+        let words = report.words_by_type.get(label).copied().unwrap_or(0);
+        let chars = report.chars_by_type.get(label).copied().unwrap_or(0);
+        println!("  {}: {} files, {} lines, {} words, {} chars", label, count, lines, words, chars);
+    }
+
+    if !report.top_words.is_empty() {
+        let top: Vec<_> = report.top_words.iter().take(10).map(|(w, c)| format!("{} ({})", w, c)).collect();
+        println!("\nProject-wide top words: {}", top.join(", "));
+    }
+
+    if report.log_errors > 0 {
+        println!("\nTotal log errors: {}", report.log_errors);
+    }
+    if report.rust_todos > 0 {
+        println!("Total Rust TODOs/FIXMEs: {}", report.rust_todos);
+    }
+}
+
+fn print_summary(summary: &FileSummary, filename: &str) {
+    println!("File Summary: {}", filename);
+
+    match summary.file_type {
+        FileType::Text => println!("Type: Plain Text"),
+        FileType::Markdown => println!("Type: Markdown"),
+        FileType::Log => println!("Type: Log File"),
+        FileType::RustCode => println!("Type: Rust Source Code"),
+        FileType::Unknown => println!("Type: Unknown"),
+    }
+
+    println!("\nBasic Statistics:");
+    println!("Lines: {}", summary.line_count);
+    println!("Words: {}", summary.word_count);
+    println!("Characters: {}", summary.char_count);
+
+    if !summary.statistics.is_empty() {
+        println!("\nDetailed Statistics:");
+        for (key, value) in &summary.statistics {
+            let display_key = key.replace("_", " ").replace("avg", "Average");
+            println!("{}: {}", display_key.chars().next().unwrap().to_uppercase().to_string() + &display_key[1..], value);
+        }
+    }
+    if !summary.key_insights.is_empty() {
+        println!("\nKey Insights:");
+        for insight in &summary.key_insights {
+            println!("   â€¢ {}", insight);
+        }
+    }
+    if !summary.summary.is_empty() {
+        println!("\nSummary:");
+        for sentence in &summary.summary {
+            println!("   â€¢ {}", sentence);
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::analyzer::TextAnalyzer;
+use crate::types::FileType;
+
+const TOP_WORDS_LIMIT: usize = 20;
+
+/// Rolled-up results from recursively analyzing a directory tree, modeled
+/// on rust-analyzer's `analysis-stats` report.
+#[derive(Debug, Default, Serialize)]
+pub struct AggregateReport {
+    pub files_processed: usize,
+    pub files_by_type: HashMap<String, usize>,
+    pub lines_by_type: HashMap<String, usize>,
+    pub words_by_type: HashMap<String, usize>,
+    pub chars_by_type: HashMap<String, usize>,
+    pub top_words: Vec<(String, usize)>,
+    pub log_errors: usize,
+    pub rust_todos: usize,
+}
+
+/// Walks `root`, analyzing every readable file and folding the results
+/// into an `AggregateReport`. Files with an unrecognized extension are
+/// analyzed as plain text and grouped under the `unknown` label. `on_progress` is called once per file with
+/// its index, the total file count, and its path, so callers can drive a
+/// progress indicator.
+pub fn walk(root: &Path, stopwords: &HashSet<String>, mut on_progress: impl FnMut(usize, usize, &Path)) -> Result<AggregateReport, String> {
+    let mut files = Vec::new();
+    collect_files(root, &mut files)?;
+    let total = files.len();
+
+    let mut report = AggregateReport::default();
+    let mut word_totals: HashMap<String, usize> = HashMap::new();
+
+    for (i, path) in files.iter().enumerate() {
+        on_progress(i + 1, total, path);
+
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(_) => continue, // binary or unreadable file; skip it
+        };
+
+        let file_type = FileType::from_extension(path);
+        let analyzer = TextAnalyzer::with_stopwords(content, stopwords.clone());
+        let summary = analyzer.analyze(file_type);
+        let label = file_type.label().to_string();
+
+        report.files_processed += 1;
+        *report.files_by_type.entry(label.clone()).or_insert(0) += 1;
+        *report.lines_by_type.entry(label.clone()).or_insert(0) += summary.line_count;
+        *report.words_by_type.entry(label.clone()).or_insert(0) += summary.word_count;
+        *report.chars_by_type.entry(label).or_insert(0) += summary.char_count;
+
+        for (word, count) in analyzer.get_word_frequency() {
+            *word_totals.entry(word).or_insert(0) += count;
+        }
+
+        if file_type == FileType::Log {
+            if let Some(errors) = summary.statistics.get("errors").and_then(|v| v.parse::<usize>().ok()) {
+                report.log_errors += errors;
+            }
+        }
+        if file_type == FileType::RustCode {
+            if let Some(todos) = summary.statistics.get("todos").and_then(|v| v.parse::<usize>().ok()) {
+                report.rust_todos += todos;
+            }
+        }
+    }
+
+    let mut top_words: Vec<_> = word_totals.into_iter().collect();
+    top_words.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    top_words.truncate(TOP_WORDS_LIMIT);
+    report.top_words = top_words;
+
+    Ok(report)
+}
+
+/// Directory names skipped entirely during the walk: VCS internals and
+/// build output, neither of which are project source a user would want
+/// reflected in an aggregate report.
+const EXCLUDED_DIRS: &[&str] = &[".git", "target"];
+
+fn collect_files(dir: &Path, out: &mut Vec<PathBuf>) -> Result<(), String> {
+    let entries = std::fs::read_dir(dir).map_err(|e| format!("cannot read '{}': {}", dir.display(), e))?;
+    for entry in entries {
+        let entry = entry.map_err(|e| e.to_string())?;
+        let path = entry.path();
+        if path.is_dir() {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            if name.starts_with('.') || EXCLUDED_DIRS.contains(&name.as_ref()) {
+                continue;
+            }
+            collect_files(&path, out)?;
+        } else {
+            out.push(path);
+        }
+    }
+    Ok(())
+}
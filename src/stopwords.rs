@@ -0,0 +1,54 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+/// Bundled default English stopwords, filtered out of `get_word_frequency`
+/// so common function words don't drown out meaningful content words.
+/// Overridable wholesale via `--stopwords <file>`.
+const DEFAULT_STOPWORDS: &[&str] = &[
+    "the", "and", "for", "are", "but", "not", "you", "all", "can", "her", "was", "one", "our",
+    "out", "day", "get", "has", "him", "his", "how", "man", "new", "now", "old", "see", "two",
+    "way", "who", "boy", "did", "its", "let", "put", "say", "she", "too", "use", "that", "this",
+    "with", "have", "from", "they", "will", "would", "there", "their", "what", "about", "which",
+    "when", "make", "like", "time", "just", "know", "take", "into", "year", "your", "good",
+    "some", "could", "them", "than", "then", "look", "only", "come", "over", "think", "also",
+    "back", "after", "work", "first", "well", "even", "want", "because", "these", "give", "most",
+    "where", "been", "were", "being", "such", "here", "more", "each", "other", "shall", "while",
+];
+
+pub fn default_stopwords() -> HashSet<String> {
+    DEFAULT_STOPWORDS.iter().map(|s| s.to_string()).collect()
+}
+
+/// Loads a newline-separated stopword list, one lowercase word per line,
+/// blank lines ignored. Replaces the bundled default set entirely.
+pub fn load_stopwords(path: &Path) -> Result<HashSet<String>, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("cannot read stopwords file '{}': {}", path.display(), e))?;
+    Ok(content
+        .lines()
+        .map(|line| line.trim().to_lowercase())
+        .filter(|line| !line.is_empty())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load_stopwords_trims_lowercases_and_skips_blank_lines() {
+        let path = std::env::temp_dir().join("text-summarizer-test-stopwords.txt");
+        std::fs::write(&path, "  Foo  \nBAR\n\nbaz\n").unwrap();
+
+        let loaded = load_stopwords(&path).unwrap();
+
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, HashSet::from(["foo".to_string(), "bar".to_string(), "baz".to_string()]));
+    }
+
+    #[test]
+    fn load_stopwords_reports_missing_file() {
+        let path = std::env::temp_dir().join("text-summarizer-test-stopwords-missing.txt");
+        assert!(load_stopwords(&path).is_err());
+    }
+}
@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+
+use crate::types::FileType;
+
+pub const DEFAULT_SUMMARY_LINES: usize = 3;
+
+/// Picks the `n` most salient sentences from `content`, returned in their
+/// original document order so the summary still reads coherently.
+pub fn summarize(content: &str, file_type: FileType, word_freq: &HashMap<String, usize>, n: usize) -> Vec<String> {
+    let prepared = match file_type {
+        FileType::Markdown | FileType::RustCode => strip_structural_noise(content),
+        _ => content.to_string(),
+    };
+    let sentences = split_sentences(&prepared);
+    top_sentences(sentences, word_freq, n)
+}
+
+/// Strips fenced code blocks and header lines so they don't dilute the
+/// sentence pool scored for Markdown and Rust source.
+fn strip_structural_noise(content: &str) -> String {
+    let mut out = String::new();
+    let mut in_code_block = false;
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block || trimmed.starts_with('#') {
+            continue;
+        }
+        out.push_str(line);
+        out.push(' ');
+    }
+    out
+}
+
+/// Splits on `. ! ?` boundaries, guarding against decimals (`3.14`) and
+/// abbreviations (`e.g.`) by requiring the boundary be followed by
+/// whitespace and an uppercase letter, or the end of the text.
+fn split_sentences(text: &str) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut sentences = Vec::new();
+    let mut start = 0;
+
+    for i in 0..chars.len() {
+        let c = chars[i];
+        if c != '.' && c != '!' && c != '?' {
+            continue;
+        }
+
+        let next_non_space = chars[i + 1..].iter().position(|c| !c.is_whitespace()).map(|p| i + 1 + p);
+        let is_boundary = match next_non_space {
+            None => true,
+            Some(j) => j > i + 1 && chars[j].is_uppercase(),
+        };
+
+        let prev_is_digit = i > 0 && chars[i - 1].is_ascii_digit();
+        let next_is_digit = i + 1 < chars.len() && chars[i + 1].is_ascii_digit();
+        let is_decimal = c == '.' && prev_is_digit && next_is_digit;
+
+        if is_boundary && !is_decimal {
+            let sentence: String = chars[start..=i].iter().collect();
+            let trimmed = sentence.trim();
+            if !trimmed.is_empty() {
+                sentences.push(trimmed.to_string());
+            }
+            start = next_non_space.unwrap_or(chars.len());
+        }
+    }
+
+    if start < chars.len() {
+        let tail: String = chars[start..].iter().collect();
+        let trimmed = tail.trim();
+        if !trimmed.is_empty() {
+            sentences.push(trimmed.to_string());
+        }
+    }
+
+    sentences
+}
+
+/// Scores a sentence as its total word frequency normalized by length, so
+/// long sentences aren't favored just for covering more frequent words.
+fn score(sentence: &str, word_freq: &HashMap<String, usize>) -> f64 {
+    let words: Vec<String> = sentence
+        .split_whitespace()
+        .map(|w| w.to_lowercase().chars().filter(|c| c.is_alphabetic()).collect::<String>())
+        .filter(|w| !w.is_empty())
+        .collect();
+
+    if words.is_empty() {
+        return 0.0;
+    }
+
+    let total: usize = words.iter().map(|w| word_freq.get(w).copied().unwrap_or(0)).sum();
+    total as f64 / words.len() as f64
+}
+
+fn top_sentences(sentences: Vec<String>, word_freq: &HashMap<String, usize>, n: usize) -> Vec<String> {
+    let scores: Vec<f64> = sentences.iter().map(|s| score(s, word_freq)).collect();
+
+    let mut ranked: Vec<usize> = (0..sentences.len()).collect();
+    ranked.sort_by(|&a, &b| scores[b].partial_cmp(&scores[a]).unwrap_or(std::cmp::Ordering::Equal));
+
+    let mut selected: Vec<usize> = ranked.into_iter().take(n).collect();
+    selected.sort_unstable();
+    selected.into_iter().map(|i| sentences[i].clone()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_sentences_does_not_split_on_lowercase_abbreviations() {
+        let sentences = split_sentences("Bring snacks, e.g. chips and dip. Everyone will be hungry.");
+        assert_eq!(sentences, vec!["Bring snacks, e.g. chips and dip.", "Everyone will be hungry."]);
+    }
+
+    #[test]
+    fn split_sentences_does_not_split_on_decimals() {
+        let sentences = split_sentences("Pi is about 3.14 and that is neat. It repeats forever.");
+        assert_eq!(sentences, vec!["Pi is about 3.14 and that is neat.", "It repeats forever."]);
+    }
+
+    #[test]
+    fn split_sentences_splits_on_terminal_punctuation() {
+        let sentences = split_sentences("Is this real? Yes! It is true.");
+        assert_eq!(sentences, vec!["Is this real?", "Yes!", "It is true."]);
+    }
+}
@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde::Serialize;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FileSummary {
+    pub file_type: FileType,
+    pub line_count: usize,
+    pub word_count: usize,
+    pub char_count: usize,
+    pub key_insights: Vec<String>,
+    pub statistics: HashMap<String, String>,
+    /// Top-scoring sentences selected by the extractive summarizer, in
+    /// their original document order. Empty when summarization doesn't
+    /// apply (e.g. log files) or wasn't requested.
+    pub summary: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileType {
+    Text,
+    Markdown,
+    Log,
+    RustCode,
+    Unknown,
+}
+
+impl FileType {
+    pub fn from_extension(path: &Path) -> Self {
+        match path.extension().and_then(|s| s.to_str()) {
+            Some("txt") => FileType::Text,
+            Some("md") => FileType::Markdown,
+            Some("log") => FileType::Log,
+            Some("rs") => FileType::RustCode,
+            _ => FileType::Unknown,
+        }
+    }
+
+    /// Parses a `--type` override value such as "txt" or "rs".
+    pub fn from_type_flag(s: &str) -> Option<Self> {
+        match s {
+            "txt" => Some(FileType::Text),
+            "md" => Some(FileType::Markdown),
+            "log" => Some(FileType::Log),
+            "rs" => Some(FileType::RustCode),
+            _ => None,
+        }
+    }
+
+    /// Short lowercase label used as a grouping key in aggregate reports.
+    pub fn label(&self) -> &'static str {
+        match self {
+            FileType::Text => "text",
+            FileType::Markdown => "markdown",
+            FileType::Log => "log",
+            FileType::RustCode => "rust_code",
+            FileType::Unknown => "unknown",
+        }
+    }
+}